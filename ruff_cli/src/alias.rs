@@ -0,0 +1,209 @@
+//! Resolution of user-defined subcommand aliases declared under
+//! `[tool.ruff.alias]` in `pyproject.toml`.
+//!
+//! Mirrors how Cargo expands config-defined command aliases: an unrecognized
+//! first positional argument is looked up in the alias table and, if found,
+//! its value is split on whitespace and spliced back into `argv` in place of
+//! the alias itself before argument parsing runs.
+
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use ruff::resolver::PyprojectDiscovery;
+
+/// The maximum number of alias expansions to perform before assuming the
+/// alias table is self-referential.
+const MAX_EXPANSION_DEPTH: usize = 8;
+
+/// The built-in subcommands, used both to avoid shadowing and to power
+/// "did you mean" suggestions when a token matches neither a command nor an
+/// alias.
+const BUILTIN_COMMANDS: &[&str] = &["check", "add-noqa", "config", "linter", "rule", "clean"];
+
+/// Look up the `[tool.ruff.alias]` table for the given settings.
+fn alias_map(pyproject_strategy: &PyprojectDiscovery) -> HashMap<String, String> {
+    let settings = match pyproject_strategy {
+        PyprojectDiscovery::Fixed(settings) => settings,
+        PyprojectDiscovery::Hierarchical(settings) => settings,
+    };
+    settings.lib.alias.clone()
+}
+
+/// Given the raw CLI arguments (program name first), expand a leading alias
+/// token (if any) after the program name into its underlying argument list,
+/// re-expanding recursively until the first token resolves to a built-in
+/// command or no further alias applies. The program name is kept as is.
+///
+/// Returns the (possibly) expanded argument list. Bails with a clear error if
+/// the alias table is self-referential.
+///
+/// This is the first thing `ruff_cli`'s `main` does with `std::env::args`,
+/// before handing off to `Cli::parse_from`, so that an alias can expand to
+/// e.g. `check --fix` and still go through normal argument parsing.
+pub fn expand_aliases(args: Vec<String>, pyproject_strategy: &PyprojectDiscovery) -> Result<Vec<String>> {
+    let mut args = args.into_iter();
+    let Some(program) = args.next() else {
+        return Ok(vec![]);
+    };
+    let mut expanded = vec![program];
+    expanded.extend(expand_aliases_with(args.collect(), &alias_map(pyproject_strategy))?);
+    Ok(expanded)
+}
+
+/// The alias-expansion logic itself, decoupled from looking the alias table
+/// up out of a [`PyprojectDiscovery`] so it can be unit-tested directly.
+fn expand_aliases_with(args: Vec<String>, aliases: &HashMap<String, String>) -> Result<Vec<String>> {
+    if aliases.is_empty() {
+        return Ok(args);
+    }
+
+    let mut args = args;
+    let mut seen = vec![];
+    for _ in 0..MAX_EXPANSION_DEPTH {
+        let Some(first) = args.first() else {
+            return Ok(args);
+        };
+        if BUILTIN_COMMANDS.contains(&first.as_str()) {
+            return Ok(args);
+        }
+        let Some(expansion) = aliases.get(first) else {
+            return Ok(args);
+        };
+        if seen.contains(first) {
+            bail!("alias `{first}` is self-referential");
+        }
+        seen.push(first.clone());
+
+        let mut expanded: Vec<String> = expansion.split_whitespace().map(str::to_owned).collect();
+        expanded.extend(args.into_iter().skip(1));
+        args = expanded;
+    }
+    bail!(
+        "alias expansion exceeded {MAX_EXPANSION_DEPTH} levels; check `[tool.ruff.alias]` for a cycle"
+    )
+}
+
+/// Compute the Levenshtein distance between two strings.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cur = row[j + 1];
+            row[j + 1] = if ca == cb {
+                prev
+            } else {
+                1 + prev.min(row[j]).min(row[j + 1])
+            };
+            prev = cur;
+        }
+    }
+    row[b.len()]
+}
+
+/// Suggest the closest alias or built-in command name to an unrecognized
+/// token, for use in the "no such subcommand" error path.
+pub fn suggest_command(token: &str, pyproject_strategy: &PyprojectDiscovery) -> Option<String> {
+    let aliases = alias_map(pyproject_strategy);
+    let candidates = BUILTIN_COMMANDS
+        .iter()
+        .map(|command| (*command).to_string())
+        .chain(aliases.into_keys());
+    suggest_command_among(token, candidates)
+}
+
+/// The suggestion logic itself, decoupled from sourcing the candidate list
+/// out of a [`PyprojectDiscovery`] so it can be unit-tested directly.
+fn suggest_command_among(token: &str, candidates: impl Iterator<Item = String>) -> Option<String> {
+    candidates
+        .map(|candidate| (levenshtein(token, &candidate), candidate))
+        .filter(|(distance, _)| *distance <= 3)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{expand_aliases_with, levenshtein, suggest_command_among, BUILTIN_COMMANDS};
+
+    fn aliases(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(alias, expansion)| ((*alias).to_string(), (*expansion).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn expand_aliases_leaves_builtin_commands_untouched() {
+        let table = aliases(&[("lint", "check --fix")]);
+        let args = vec!["check".to_string(), "src/".to_string()];
+        assert_eq!(expand_aliases_with(args.clone(), &table).unwrap(), args);
+    }
+
+    #[test]
+    fn expand_aliases_splices_in_the_expansion() {
+        let table = aliases(&[("lint", "check --fix")]);
+        let args = vec!["lint".to_string(), "src/".to_string()];
+        assert_eq!(
+            expand_aliases_with(args, &table).unwrap(),
+            vec!["check".to_string(), "--fix".to_string(), "src/".to_string()]
+        );
+    }
+
+    #[test]
+    fn expand_aliases_resolves_transitively() {
+        let table = aliases(&[("l", "lint"), ("lint", "check --fix")]);
+        let args = vec!["l".to_string()];
+        assert_eq!(
+            expand_aliases_with(args, &table).unwrap(),
+            vec!["check".to_string(), "--fix".to_string()]
+        );
+    }
+
+    #[test]
+    fn expand_aliases_rejects_self_reference() {
+        let table = aliases(&[("l", "l")]);
+        let args = vec!["l".to_string()];
+        assert!(expand_aliases_with(args, &table).is_err());
+    }
+
+    #[test]
+    fn expand_aliases_rejects_cycles() {
+        let table = aliases(&[("a", "b"), ("b", "a")]);
+        let args = vec!["a".to_string()];
+        assert!(expand_aliases_with(args, &table).is_err());
+    }
+
+    #[test]
+    fn expand_aliases_is_a_noop_with_an_empty_table() {
+        let args = vec!["anything".to_string()];
+        assert_eq!(expand_aliases_with(args.clone(), &HashMap::new()).unwrap(), args);
+    }
+
+    #[test]
+    fn levenshtein_matches_known_distances() {
+        assert_eq!(levenshtein("check", "check"), 0);
+        assert_eq!(levenshtein("chekc", "check"), 2);
+        assert_eq!(levenshtein("", "abc"), 3);
+    }
+
+    #[test]
+    fn suggest_command_finds_a_close_builtin() {
+        let candidates = BUILTIN_COMMANDS.iter().map(|command| (*command).to_string());
+        assert_eq!(
+            suggest_command_among("chek", candidates),
+            Some("check".to_string())
+        );
+    }
+
+    #[test]
+    fn suggest_command_returns_none_when_nothing_is_close() {
+        let candidates = BUILTIN_COMMANDS.iter().map(|command| (*command).to_string());
+        assert_eq!(suggest_command_among("xyzxyzxyz", candidates), None);
+    }
+}
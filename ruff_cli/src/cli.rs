@@ -0,0 +1,136 @@
+//! The `ruff` command-line surface: argument parsing and subcommand
+//! dispatch to the functions in [`crate::commands`].
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+use ruff::fix;
+use ruff::logging::LogLevel;
+use ruff::registry::RuleCode;
+use ruff::resolver::{FileDiscovery, PyprojectDiscovery};
+use ruff::settings::flags;
+use ruff::settings::types::SerializationFormat;
+
+use crate::commands;
+
+/// Settings overrides supplied on the command line (e.g. `--select`,
+/// `--line-length`), layered on top of whatever `pyproject.toml` resolves
+/// to for a given file.
+#[derive(Default, Debug, clap::Args)]
+pub struct Overrides {
+    #[arg(long, value_delimiter = ',')]
+    pub select: Option<Vec<RuleCode>>,
+    #[arg(long, value_delimiter = ',')]
+    pub ignore: Option<Vec<RuleCode>>,
+    #[arg(long)]
+    pub line_length: Option<usize>,
+}
+
+#[derive(Parser)]
+#[command(about = "An extremely fast Python linter.", long_about = None)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Subcommand)]
+pub enum Commands {
+    /// Run Ruff over the given files or directories.
+    Check {
+        files: Vec<PathBuf>,
+        #[command(flatten)]
+        overrides: Overrides,
+        #[arg(long)]
+        fix: bool,
+    },
+    /// Add `noqa` directives to silence existing violations.
+    AddNoqa {
+        files: Vec<PathBuf>,
+        #[command(flatten)]
+        overrides: Overrides,
+    },
+    /// Print the resolved settings for the given path.
+    Config {
+        files: Vec<PathBuf>,
+        #[command(flatten)]
+        overrides: Overrides,
+    },
+    /// List the files that would be checked for the given path.
+    Linter {
+        files: Vec<PathBuf>,
+        #[command(flatten)]
+        overrides: Overrides,
+    },
+    /// Explain a rule code.
+    Rule {
+        code: RuleCode,
+        #[arg(long, value_enum, default_value_t = SerializationFormat::Text)]
+        format: SerializationFormat,
+    },
+    /// Clear Ruff's cache.
+    Clean {
+        #[arg(long, value_enum, default_value_t = LogLevel::Default)]
+        log_level: LogLevel,
+    },
+}
+
+/// Expand `args` against `[tool.ruff.alias]` and parse the result. On an
+/// unrecognized subcommand, prints clap's usual error plus a "did you mean"
+/// suggestion (if one is close enough) before exiting, the same way clap's
+/// own `DidYouMean` suggestions for flags are surfaced.
+pub fn parse_args(args: Vec<String>, pyproject_strategy: &PyprojectDiscovery) -> Result<Cli> {
+    let expanded = crate::alias::expand_aliases(args, pyproject_strategy)?;
+
+    match Cli::try_parse_from(&expanded) {
+        Ok(cli) => Ok(cli),
+        Err(err) => {
+            if err.kind() == clap::error::ErrorKind::InvalidSubcommand {
+                if let Some(token) = expanded.get(1) {
+                    if let Some(suggestion) = crate::alias::suggest_command(token, pyproject_strategy) {
+                        eprintln!("  note: did you mean `{suggestion}`?");
+                    }
+                }
+            }
+            err.exit();
+        }
+    }
+}
+
+/// Resolve a parsed [`Cli`] into a call against the matching function in
+/// [`crate::commands`].
+pub fn run(
+    cli: Cli,
+    pyproject_strategy: &PyprojectDiscovery,
+    file_strategy: &FileDiscovery,
+) -> Result<()> {
+    match cli.command {
+        Commands::Check { files, overrides, fix } => {
+            let autofix = if fix { fix::FixMode::Apply } else { fix::FixMode::None };
+            commands::run(
+                &files,
+                pyproject_strategy,
+                file_strategy,
+                &overrides,
+                flags::Cache::Enabled,
+                autofix,
+            )?;
+        }
+        Commands::AddNoqa { files, overrides } => {
+            commands::add_noqa(&files, pyproject_strategy, file_strategy, &overrides)?;
+        }
+        Commands::Config { files, overrides } => {
+            commands::show_settings(&files, pyproject_strategy, file_strategy, &overrides)?;
+        }
+        Commands::Linter { files, overrides } => {
+            commands::show_files(&files, pyproject_strategy, file_strategy, &overrides)?;
+        }
+        Commands::Rule { code, format } => {
+            commands::explain(&code, format)?;
+        }
+        Commands::Clean { log_level } => {
+            commands::clean(&log_level)?;
+        }
+    }
+    Ok(())
+}
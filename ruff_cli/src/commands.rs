@@ -1,3 +1,4 @@
+use std::fmt;
 use std::fs::remove_dir_all;
 use std::io::{self, Read};
 use std::path::{Path, PathBuf};
@@ -28,6 +29,106 @@ use crate::cli::Overrides;
 use crate::diagnostics::{lint_path, lint_stdin, Diagnostics};
 use crate::iterators::par_iter;
 
+/// The kind of non-regular or inaccessible file that was explicitly passed on
+/// the command line.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum BadFileKind {
+    CharacterDevice,
+    BlockDevice,
+    Fifo,
+    Socket,
+    Directory,
+    Unknown,
+}
+
+impl fmt::Display for BadFileKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BadFileKind::CharacterDevice => write!(f, "character device"),
+            BadFileKind::BlockDevice => write!(f, "block device"),
+            BadFileKind::Fifo => write!(f, "fifo"),
+            BadFileKind::Socket => write!(f, "socket"),
+            BadFileKind::Directory => write!(f, "directory"),
+            BadFileKind::Unknown => write!(f, "unknown file type"),
+        }
+    }
+}
+
+/// Classify a path that was explicitly specified by the user but that can't
+/// be checked as a normal file, e.g. a FIFO or a socket. Returns `None` for
+/// regular files, symlinks, directories, and paths that can't be stat'd.
+// A directory is a perfectly normal argument (`ruff .`, `ruff src/`) and is
+// deliberately NOT classified here: whether it's "checkable" depends on
+// whether discovery found any files under it, which only the caller knows.
+// See `directory_bad_file_message`, used once discovery comes back empty.
+
+#[cfg(target_family = "unix")]
+fn classify_bad_file(path: &Path) -> Option<BadFileKind> {
+    use std::os::unix::fs::FileTypeExt;
+
+    let file_type = path.symlink_metadata().ok()?.file_type();
+    if file_type.is_file() || file_type.is_symlink() || file_type.is_dir() {
+        return None;
+    }
+    Some(if file_type.is_char_device() {
+        BadFileKind::CharacterDevice
+    } else if file_type.is_block_device() {
+        BadFileKind::BlockDevice
+    } else if file_type.is_fifo() {
+        BadFileKind::Fifo
+    } else if file_type.is_socket() {
+        BadFileKind::Socket
+    } else {
+        BadFileKind::Unknown
+    })
+}
+
+#[cfg(not(target_family = "unix"))]
+fn classify_bad_file(path: &Path) -> Option<BadFileKind> {
+    let file_type = path.symlink_metadata().ok()?.file_type();
+    if file_type.is_file() || file_type.is_symlink() || file_type.is_dir() {
+        return None;
+    }
+    Some(BadFileKind::Unknown)
+}
+
+/// Build a [`Message`] reporting that an explicitly-specified directory
+/// resolved to nothing checkable, i.e. discovery found zero Python files
+/// under it. Only meaningful once the caller knows discovery came back
+/// empty -- an ordinary directory full of Python files is not "bad".
+fn directory_bad_file_message(path: &Path) -> Option<Message> {
+    if !path.is_dir() {
+        return None;
+    }
+    Some(Message {
+        kind: IOError(format!(
+            "cannot check '{}': {}",
+            path.to_string_lossy(),
+            BadFileKind::Directory
+        ))
+        .into(),
+        location: Location::default(),
+        end_location: Location::default(),
+        fix: None,
+        filename: path.to_string_lossy().to_string(),
+        source: None,
+    })
+}
+
+/// Build a [`Message`] reporting that an explicitly-specified path could not
+/// be checked, if it's a known non-regular file kind.
+fn bad_file_message(path: &Path) -> Option<Message> {
+    let kind = classify_bad_file(path)?;
+    Some(Message {
+        kind: IOError(format!("cannot check '{}': {kind}", path.to_string_lossy())).into(),
+        location: Location::default(),
+        end_location: Location::default(),
+        fix: None,
+        filename: path.to_string_lossy().to_string(),
+        source: None,
+    })
+}
+
 /// Run the linter over a collection of files.
 pub fn run(
     files: &[PathBuf],
@@ -37,6 +138,12 @@ pub fn run(
     cache: flags::Cache,
     autofix: fix::FixMode,
 ) -> Result<Diagnostics> {
+    // Classify any explicitly-specified paths that can't be checked as regular
+    // files (e.g. a FIFO or a socket), so users get a clear reason instead of
+    // an opaque read failure. Directories are only reported below, once
+    // discovery has come back empty.
+    let bad_file_messages: Vec<Message> = files.iter().filter_map(|path| bad_file_message(path)).collect();
+
     // Collect all the Python files to check.
     let start = Instant::now();
     let (paths, resolver) =
@@ -45,8 +152,12 @@ pub fn run(
     debug!("Identified files to lint in: {:?}", duration);
 
     if paths.is_empty() {
-        warn_user_once!("No Python files found under the given path(s)");
-        return Ok(Diagnostics::default());
+        let mut messages = bad_file_messages;
+        messages.extend(files.iter().filter_map(|path| directory_bad_file_message(path)));
+        if messages.is_empty() {
+            warn_user_once!("No Python files found under the given path(s)");
+        }
+        return Ok(Diagnostics::new(messages));
     }
 
     // Validate the `Settings` and return any errors.
@@ -138,6 +249,7 @@ pub fn run(
             acc
         });
 
+    diagnostics += Diagnostics::new(bad_file_messages);
     diagnostics.messages.sort_unstable();
     let duration = start.elapsed();
     debug!("Checked files in: {:?}", duration);
@@ -161,6 +273,9 @@ pub fn run_stdin(
     autofix: fix::FixMode,
 ) -> Result<Diagnostics> {
     if let Some(filename) = filename {
+        if let Some(message) = bad_file_message(filename) {
+            return Ok(Diagnostics::new(vec![message]));
+        }
         if !resolver::python_file_at_path(filename, pyproject_strategy, file_strategy, overrides)? {
             return Ok(Diagnostics::default());
         }
@@ -258,6 +373,14 @@ pub fn show_files(
     file_strategy: &FileDiscovery,
     overrides: &Overrides,
 ) -> Result<()> {
+    // Surface a clear reason for any explicitly-specified path that can't be
+    // checked as a regular file.
+    for path in files {
+        if let Some(kind) = classify_bad_file(path) {
+            error!("cannot check '{}': {kind}", path.to_string_lossy());
+        }
+    }
+
     // Collect all files in the hierarchy.
     let (paths, resolver) =
         resolver::python_files_in_path(files, pyproject_strategy, file_strategy, overrides)?;
@@ -340,3 +463,63 @@ pub fn clean(level: &LogLevel) -> Result<()> {
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{bad_file_message, classify_bad_file, directory_bad_file_message, BadFileKind};
+
+    fn unique_temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("ruff-commands-test-{}-{name}", std::process::id()))
+    }
+
+    #[test]
+    fn classify_bad_file_ignores_regular_directories() {
+        let dir = unique_temp_path("dir");
+        std::fs::create_dir_all(&dir).unwrap();
+        assert_eq!(classify_bad_file(&dir), None);
+        assert!(bad_file_message(&dir).is_none());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn classify_bad_file_ignores_regular_files() {
+        let file = unique_temp_path("file.py");
+        std::fs::write(&file, "pass\n").unwrap();
+        assert_eq!(classify_bad_file(&file), None);
+        assert!(bad_file_message(&file).is_none());
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[cfg(target_family = "unix")]
+    #[test]
+    fn classify_bad_file_flags_fifo() {
+        let fifo = unique_temp_path("fifo");
+        let status = std::process::Command::new("mkfifo")
+            .arg(&fifo)
+            .status()
+            .unwrap();
+        assert!(status.success());
+        assert_eq!(classify_bad_file(&fifo), Some(BadFileKind::Fifo));
+        assert!(bad_file_message(&fifo)
+            .unwrap()
+            .filename
+            .contains("fifo"));
+        std::fs::remove_file(&fifo).unwrap();
+    }
+
+    #[test]
+    fn directory_bad_file_message_only_fires_for_directories() {
+        let dir = unique_temp_path("empty-dir");
+        std::fs::create_dir_all(&dir).unwrap();
+        assert!(directory_bad_file_message(&dir).is_some());
+
+        let file = unique_temp_path("not-a-dir.py");
+        std::fs::write(&file, "pass\n").unwrap();
+        assert!(directory_bad_file_message(&file).is_none());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        std::fs::remove_file(&file).unwrap();
+    }
+}
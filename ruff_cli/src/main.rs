@@ -0,0 +1,31 @@
+//! The `ruff` executable entry point.
+
+use std::process::ExitCode;
+
+use anyhow::Result;
+use colored::Colorize;
+use path_absolutize::path_dedot;
+use ruff::resolver::{self, Relativity};
+
+mod alias;
+mod cli;
+mod commands;
+
+fn main() -> Result<ExitCode> {
+    let args: Vec<String> = std::env::args().collect();
+
+    // Resolve settings against the current directory before we've even
+    // parsed which subcommand was requested: the `[tool.ruff.alias]` table
+    // alias expansion reads from lives there, same as any other setting.
+    let (pyproject_strategy, file_strategy) =
+        resolver::resolve_settings(&path_dedot::CWD, &Relativity::Cwd)?;
+
+    let cli = cli::parse_args(args, &pyproject_strategy)?;
+
+    if let Err(err) = cli::run(cli, &pyproject_strategy, &file_strategy) {
+        eprintln!("{}: {err}", "error".red().bold());
+        return Ok(ExitCode::FAILURE);
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,209 @@
+//! Generate the boilerplate for a new rule, following `clippy_dev`'s
+//! `new_lint`: a stub rule function in the right module, a `RuleCode` and
+//! `violations` registration, and an empty snapshot test fixture.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use clap::Args as ClapArgs;
+
+#[derive(ClapArgs)]
+pub struct Args {
+    /// The rule code to scaffold, e.g. `SIM115`.
+    #[arg(long)]
+    pub code: String,
+    /// The plugin the rule belongs to, e.g. `flake8-simplify`.
+    #[arg(long)]
+    pub plugin: String,
+    /// Whether the rule is expected to carry an autofix.
+    #[arg(long, default_value_t = false)]
+    pub fixable: bool,
+}
+
+/// Convert a plugin name like `flake8-simplify` into its module name,
+/// `flake8_simplify`.
+fn plugin_module(plugin: &str) -> String {
+    plugin.replace('-', "_")
+}
+
+/// Derive a placeholder `snake_case` function name and `PascalCase`
+/// violation name from a rule code, e.g. `SIM115` -> (`stub_sim115`,
+/// `StubSIM115`).
+///
+/// Since the generator can't guess a meaningful name from the bare code, it
+/// emits a placeholder derived from the code itself; the contributor is
+/// expected to rename it before filling in the rule body.
+fn stub_names(code: &str) -> (String, String) {
+    let snake_case = format!("stub_{}", code.to_lowercase());
+    let pascal_case = format!("Stub{code}");
+    (snake_case, pascal_case)
+}
+
+fn rule_stub(code: &str, fn_name: &str, violation_name: &str, fixable: bool) -> String {
+    let patch_block = if fixable {
+        format!(
+            "\n    if checker.patch(&RuleCode::{code}) {{\n        // TODO(you): amend `diagnostic` with a `Fix`.\n    }}\n"
+        )
+    } else {
+        String::new()
+    };
+    format!(
+        "use rustpython_ast::Stmt;\n\
+\n\
+use crate::ast::types::Range;\n\
+use crate::checkers::ast::Checker;\n\
+use crate::registry::{{Diagnostic, RuleCode}};\n\
+use crate::violations;\n\
+\n\
+/// {code}\n\
+pub fn {fn_name}(checker: &mut Checker, stmt: &Stmt) {{\n\
+    // TODO(you): implement the check.\n\
+    let diagnostic = Diagnostic::new(violations::{violation_name}, Range::from_located(stmt));\n\
+{patch_block}\
+    checker.diagnostics.push(diagnostic);\n\
+}}\n"
+    )
+}
+
+fn violation_stub(code: &str, violation_name: &str, fixable: bool) -> String {
+    let fixable_note = if fixable {
+        " Carries an autofix, so its `Fix` is built at the call site in the rule function."
+    } else {
+        ""
+    };
+    format!(
+        "\n\
+/// {code}: TODO(you) -- write a one-line summary of what this violation flags.{fixable_note}\n\
+#[violation]\n\
+pub struct {violation_name};\n\
+\n\
+impl Violation for {violation_name} {{\n\
+    #[derive_message_formats]\n\
+    fn message(&self) -> String {{\n\
+        format!(\"TODO(you): write the user-facing message for {code}\")\n\
+    }}\n\
+}}\n"
+    )
+}
+
+/// Insert `variant` as a new line inside the `pub enum RuleCode { ... }`
+/// block in `path`, immediately after the opening brace. Bails out (rather
+/// than guessing further) if the enum can't be found, so a contributor is
+/// told exactly what to fix by hand instead of getting a silently
+/// half-registered rule.
+fn insert_rule_code_variant(path: &Path, variant: &str) -> Result<()> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+
+    if contents.contains(&format!("    {variant},\n")) {
+        bail!("`RuleCode::{variant}` is already registered in {}", path.display());
+    }
+
+    let marker = "pub enum RuleCode {";
+    let Some(marker_start) = contents.find(marker) else {
+        bail!(
+            "couldn't find `{marker}` in {}; register `RuleCode::{variant}` by hand",
+            path.display()
+        );
+    };
+    let insert_at = marker_start + marker.len();
+    let Some(line_end) = contents[insert_at..].find('\n') else {
+        bail!("malformed `RuleCode` enum in {}", path.display());
+    };
+    let insert_at = insert_at + line_end + 1;
+
+    let mut updated = contents;
+    updated.insert_str(insert_at, &format!("    {variant},\n"));
+    fs::write(path, updated).with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// Append a new violation struct stub to the end of `path`. Unlike the
+/// `RuleCode` enum, top-level struct and impl definitions are valid anywhere
+/// in the module, so this doesn't need to locate an insertion point -- it's
+/// the same approach `clippy_dev new_lint` takes for appending a lint's
+/// `declare_clippy_lint!` invocation.
+fn append_violation_stub(path: &Path, code: &str, violation_name: &str, fixable: bool) -> Result<()> {
+    let mut contents = fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+
+    if contents.contains(&format!("pub struct {violation_name};")) {
+        bail!(
+            "`violations::{violation_name}` is already defined in {}",
+            path.display()
+        );
+    }
+
+    contents.push_str(&violation_stub(code, violation_name, fixable));
+    fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// Append `pub mod {module};` to the `rules/mod.rs` at `path`, so the newly
+/// generated rule file is actually compiled in, the way `clippy_dev
+/// new_lint` updates `clippy_lints/src/lib.rs`'s module list. Creates the
+/// file (with just that one line) if the plugin doesn't have a `rules/
+/// mod.rs` yet.
+fn register_rule_module(path: &Path, module: &str) -> Result<()> {
+    let mut contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err).with_context(|| format!("failed to read {}", path.display())),
+    };
+
+    let declaration = format!("pub mod {module};");
+    if contents.lines().any(|line| line == declaration) {
+        bail!("`{declaration}` is already present in {}", path.display());
+    }
+
+    if !contents.is_empty() && !contents.ends_with('\n') {
+        contents.push('\n');
+    }
+    contents.push_str(&declaration);
+    contents.push('\n');
+    fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))
+}
+
+pub fn main(args: &Args) -> Result<()> {
+    let code = args.code.trim().to_uppercase();
+    if code.is_empty() {
+        bail!("--code must not be empty");
+    }
+    let (fn_name, violation_name) = stub_names(&code);
+    let module = plugin_module(&args.plugin);
+
+    let rules_dir = PathBuf::from("src/rules").join(&module).join("rules");
+    fs::create_dir_all(&rules_dir)?;
+    let rule_path = rules_dir.join(format!("{fn_name}.rs"));
+    if rule_path.exists() {
+        bail!("{} already exists", rule_path.display());
+    }
+    fs::write(&rule_path, rule_stub(&code, &fn_name, &violation_name, args.fixable))?;
+
+    let mod_path = rules_dir.join("mod.rs");
+    register_rule_module(&mod_path, &fn_name)?;
+
+    let fixtures_dir = PathBuf::from("resources/test/fixtures").join(&module);
+    fs::create_dir_all(&fixtures_dir)?;
+    let fixture_path = fixtures_dir.join(format!("{code}.py"));
+    if !fixture_path.exists() {
+        fs::write(&fixture_path, "# TODO(you): add a minimal repro for the new rule.\n")?;
+    }
+
+    let registry_path = PathBuf::from("src/registry.rs");
+    insert_rule_code_variant(&registry_path, &code)?;
+
+    let violations_path = PathBuf::from("src/violations.rs");
+    append_violation_stub(&violations_path, &code, &violation_name, args.fixable)?;
+
+    println!("Generated {}", rule_path.display());
+    println!("Registered `pub mod {fn_name};` in {}", mod_path.display());
+    println!("Generated {}", fixture_path.display());
+    println!("Registered RuleCode::{code} in {}", registry_path.display());
+    println!("Registered violations::{violation_name} in {}", violations_path.display());
+    println!(
+        "Next steps: wire `{fn_name}` up from the relevant checker, fill in the TODOs in the \
+         generated files, and add a snapshot test."
+    );
+
+    Ok(())
+}
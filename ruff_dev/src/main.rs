@@ -0,0 +1,29 @@
+//! Developer utilities for working on Ruff itself, modeled on `clippy_dev`.
+#![forbid(unsafe_code)]
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+
+mod commands;
+
+#[derive(Parser)]
+#[command(about = "Developer utilities for working on Ruff.", long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Generate a scaffold for a new rule: a stub rule function, a
+    /// `RuleCode`/`violations` registration, and an empty snapshot test
+    /// fixture.
+    NewRule(commands::new_rule::Args),
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Commands::NewRule(args) => commands::new_rule::main(&args),
+    }
+}
@@ -6,10 +6,11 @@ use nohash_hasher::IntMap;
 use rustpython_parser::ast::Location;
 
 use crate::ast::types::Range;
-use crate::fix::Fix;
-use crate::noqa::{is_file_exempt, Directive};
+use crate::fix::{Applicability, Fix, FixResolution};
+use crate::noqa::{is_file_exempt, Directive, FileExemption};
 use crate::registry::{Diagnostic, DiagnosticKind, RuleCode, CODE_REDIRECTS};
 use crate::settings::{flags, Settings};
+use crate::severity;
 use crate::violations::UnusedCodes;
 use crate::{noqa, violations};
 
@@ -20,18 +21,39 @@ pub fn check_noqa(
     noqa_line_for: &IntMap<usize, usize>,
     settings: &Settings,
     autofix: flags::Autofix,
+    fix_resolution: Option<FixResolution>,
 ) {
+    // If the caller doesn't care about lazy resolution, fall back to the
+    // behavior `--fix` has always had: materialize every fix eagerly when
+    // autofix is enabled, and none otherwise. Callers that *do* want finer
+    // control (e.g. an editor resolving one diagnostic at a time) pass an
+    // explicit `FixResolution`.
+    let fix_resolution = fix_resolution.unwrap_or(if matches!(autofix, flags::Autofix::Enabled) {
+        FixResolution::All
+    } else {
+        FixResolution::Unresolved
+    });
+
     let mut noqa_directives: IntMap<usize, (Directive, Vec<&str>)> = IntMap::default();
     let mut ignored = vec![];
 
     let enforce_noqa = settings.rules.enabled(&RuleCode::RUF100);
 
     let lines: Vec<&str> = contents.lines().collect();
+
+    // File-level `# ruff: noqa: ...` directives, by line. A bare
+    // `# ruff: noqa` (no codes) still exempts the whole file immediately;
+    // a directive with codes only silences those codes, so every other
+    // violation in the file is still reported.
+    let mut file_exempt_lines: Vec<(usize, Vec<&str>)> = vec![];
     for lineno in commented_lines {
-        // If we hit an exemption for the entire file, bail.
-        if is_file_exempt(lines[lineno - 1]) {
-            diagnostics.drain(..);
-            return;
+        match is_file_exempt(lines[lineno - 1]) {
+            FileExemption::All => {
+                diagnostics.drain(..);
+                return;
+            }
+            FileExemption::Codes(codes) => file_exempt_lines.push((*lineno, codes)),
+            FileExemption::None => {}
         }
 
         if enforce_noqa {
@@ -41,6 +63,57 @@ pub fn check_noqa(
         }
     }
 
+    // Filter out any diagnostics silenced by a file-level per-code directive,
+    // and track which of the directive's *tokens* were actually used (not
+    // the diagnostic codes they matched, since `noqa::includes` is a prefix
+    // match -- e.g. the token `E5` covers the code `E501`) so RUF100 can
+    // flag the rest as unused. A diagnostic can be covered by more than one
+    // token at once (e.g. both `E5` and `E501` in the same directive), so
+    // every covering token is credited, not just the first one found.
+    let mut file_exempt_matches: Vec<&str> = vec![];
+    if !file_exempt_lines.is_empty() {
+        let file_exempt_codes: Vec<&str> = file_exempt_lines
+            .iter()
+            .flat_map(|(_, codes)| codes.iter().copied())
+            .collect();
+        diagnostics.retain(|diagnostic| {
+            let covering_tokens: Vec<&str> = file_exempt_codes
+                .iter()
+                .copied()
+                .filter(|code| noqa::includes(diagnostic.kind.code(), std::slice::from_ref(*code)))
+                .collect();
+            if covering_tokens.is_empty() {
+                true
+            } else {
+                file_exempt_matches.extend(covering_tokens);
+                false
+            }
+        });
+    }
+
+    if enforce_noqa {
+        for (lineno, codes) in &file_exempt_lines {
+            let unused: Vec<String> = codes
+                .iter()
+                .filter(|code| !file_exempt_matches.contains(*code))
+                .map(|code| (*code).to_string())
+                .collect();
+            if !unused.is_empty() {
+                diagnostics.push(Diagnostic::new(
+                    violations::UnusedNOQA(Some(UnusedCodes {
+                        disabled: vec![],
+                        unknown: vec![],
+                        unmatched: unused,
+                    })),
+                    Range::new(
+                        Location::new(*lineno, 0),
+                        Location::new(*lineno, lines[lineno - 1].chars().count()),
+                    ),
+                ));
+            }
+        }
+    }
+
     // Remove any ignored diagnostics.
     for (index, diagnostic) in diagnostics.iter().enumerate() {
         if matches!(diagnostic.kind, DiagnosticKind::BlanketNOQA(..)) {
@@ -97,23 +170,39 @@ pub fn check_noqa(
         }
     }
 
-    // Enforce that the noqa directive was actually used (RUF100).
+    // Enforce that the noqa directive was actually used (RUF100). Only
+    // directives that actually emit an `UnusedNOQA` diagnostic are numbered
+    // (in row order), so `FixResolution::Single` addresses the same ordinal
+    // `check_noqa`'s caller saw when it first enumerated the emitted
+    // diagnostics -- a directive that's fully used, or that's `None`, or
+    // that's self-ignored never got an ordinal in the first place, so it
+    // can't throw off the count.
     if enforce_noqa {
-        for (row, (directive, matches)) in noqa_directives {
+        let mut sorted_directives: Vec<_> = noqa_directives.into_iter().collect();
+        sorted_directives.sort_unstable_by_key(|(row, ..)| *row);
+        let mut ordinal: usize = 0;
+        for (row, (directive, matches)) in sorted_directives {
             match directive {
                 Directive::All(spaces, start, end) => {
                     if matches.is_empty() {
+                        let id = ordinal;
+                        ordinal += 1;
                         let mut diagnostic = Diagnostic::new(
                             violations::UnusedNOQA(None),
                             Range::new(Location::new(row + 1, start), Location::new(row + 1, end)),
                         );
                         if matches!(autofix, flags::Autofix::Enabled)
                             && settings.rules.should_fix(diagnostic.kind.code())
+                            && fix_resolution.resolves(id)
                         {
-                            diagnostic.amend(Fix::deletion(
+                            let fix = Fix::deletion(
                                 Location::new(row + 1, start - spaces),
                                 Location::new(row + 1, lines[row].chars().count()),
-                            ));
+                            )
+                            .with_applicability(Applicability::MachineApplicable);
+                            if fix.applicability.meets_threshold(settings.fix_applicability_threshold) {
+                                diagnostic.amend(fix);
+                            }
                         }
                         diagnostics.push(diagnostic);
                     }
@@ -154,6 +243,8 @@ pub fn check_noqa(
                         && unknown_codes.is_empty()
                         && unmatched_codes.is_empty())
                     {
+                        let id = ordinal;
+                        ordinal += 1;
                         let mut diagnostic = Diagnostic::new(
                             violations::UnusedNOQA(Some(UnusedCodes {
                                 disabled: disabled_codes
@@ -173,18 +264,23 @@ pub fn check_noqa(
                         );
                         if matches!(autofix, flags::Autofix::Enabled)
                             && settings.rules.should_fix(diagnostic.kind.code())
+                            && fix_resolution.resolves(id)
                         {
-                            if valid_codes.is_empty() {
-                                diagnostic.amend(Fix::deletion(
+                            let fix = if valid_codes.is_empty() {
+                                Fix::deletion(
                                     Location::new(row + 1, start - spaces),
                                     Location::new(row + 1, lines[row].chars().count()),
-                                ));
+                                )
                             } else {
-                                diagnostic.amend(Fix::replacement(
+                                Fix::replacement(
                                     format!("# noqa: {}", valid_codes.join(", ")),
                                     Location::new(row + 1, start),
                                     Location::new(row + 1, lines[row].chars().count()),
-                                ));
+                                )
+                            }
+                            .with_applicability(Applicability::MachineApplicable);
+                            if fix.applicability.meets_threshold(settings.fix_applicability_threshold) {
+                                diagnostic.amend(fix);
                             }
                         }
                         diagnostics.push(diagnostic);
@@ -199,4 +295,11 @@ pub fn check_noqa(
     for index in ignored.iter().rev() {
         diagnostics.swap_remove(*index);
     }
+
+    // Drop any diagnostics below the configured minimum severity. This lets
+    // users silence advisories (e.g. RUF100's `Warning`-level unused-noqa
+    // bookkeeping) without touching rule selection.
+    if let Some(min_severity) = settings.min_severity {
+        diagnostics.retain(|diagnostic| severity::severity_for(diagnostic.kind.code(), settings) >= min_severity);
+    }
 }
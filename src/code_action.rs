@@ -0,0 +1,115 @@
+//! Structured, per-diagnostic edits for editor integrations.
+//!
+//! The batch `--fix` path consumes a [`Fix`]'s byte range and replacement
+//! text directly, but an editor wants to offer fixes one at a time, each
+//! with a human-readable title and a diff preview -- the "assist"/"code
+//! action" model used by rust-analyzer. This module maps a [`Diagnostic`]'s
+//! amended [`Fix`] into a [`CodeAction`] carrying the rule code, a title and
+//! the [`TextEdit`], without touching the eager batch path. It does not
+//! render a diff preview; only the edit itself is provided.
+
+use serde::Serialize;
+
+use crate::fix::Fix;
+use crate::message::Location;
+use crate::registry::Diagnostic;
+
+/// A single, independently-addressable source edit, ready to hand to an
+/// editor's "apply edit" API.
+#[derive(Debug, Clone, Serialize)]
+pub struct TextEdit {
+    pub location: Location,
+    pub end_location: Location,
+    pub new_text: String,
+}
+
+impl From<&Fix> for TextEdit {
+    fn from(fix: &Fix) -> Self {
+        Self {
+            location: fix.location,
+            end_location: fix.end_location,
+            new_text: fix.content.clone(),
+        }
+    }
+}
+
+/// A named, addressable fix for a single [`Diagnostic`], suitable for
+/// surfacing as an editor code action.
+#[derive(Debug, Clone, Serialize)]
+pub struct CodeAction {
+    /// The rule code the action resolves, e.g. `SIM401`.
+    pub code: String,
+    /// A human-readable title, e.g. "Use `dict.get` with default".
+    pub title: String,
+    pub edit: TextEdit,
+}
+
+/// Build the [`CodeAction`] for a diagnostic's amended fix, if it has one.
+pub fn code_action(diagnostic: &Diagnostic) -> Option<CodeAction> {
+    let fix = diagnostic.fix.as_ref()?;
+    Some(CodeAction {
+        code: diagnostic.kind.code().as_ref().to_string(),
+        title: diagnostic.kind.body(),
+        edit: TextEdit::from(fix),
+    })
+}
+
+/// Build the list of available code actions for a set of diagnostics,
+/// skipping any that weren't amended with a fix.
+pub fn code_actions(diagnostics: &[Diagnostic]) -> Vec<CodeAction> {
+    diagnostics.iter().filter_map(code_action).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rustpython_parser::ast::Location;
+
+    use super::{code_action, code_actions};
+    use crate::ast::types::Range;
+    use crate::fix::Fix;
+    use crate::registry::Diagnostic;
+    use crate::violations;
+
+    fn diagnostic_with_fix() -> Diagnostic {
+        let mut diagnostic = Diagnostic::new(
+            violations::UseTernaryOperator("x = a if c else b".to_string()),
+            Range::new(Location::new(1, 0), Location::new(1, 18)),
+        );
+        diagnostic.amend(Fix::replacement(
+            "x = a if c else b".to_string(),
+            Location::new(1, 0),
+            Location::new(1, 18),
+        ));
+        diagnostic
+    }
+
+    #[test]
+    fn code_action_maps_an_amended_diagnostic_to_its_edit() {
+        let diagnostic = diagnostic_with_fix();
+        let action = code_action(&diagnostic).expect("diagnostic was amended with a fix");
+        assert_eq!(action.edit.new_text, "x = a if c else b");
+        assert_eq!(action.edit.location, Location::new(1, 0));
+        assert_eq!(action.edit.end_location, Location::new(1, 18));
+    }
+
+    #[test]
+    fn code_action_skips_diagnostics_without_a_fix() {
+        let diagnostic = Diagnostic::new(
+            violations::UseTernaryOperator("x = a if c else b".to_string()),
+            Range::new(Location::new(1, 0), Location::new(1, 18)),
+        );
+        assert!(code_action(&diagnostic).is_none());
+    }
+
+    #[test]
+    fn code_actions_filters_out_diagnostics_without_fixes() {
+        let diagnostics = vec![
+            diagnostic_with_fix(),
+            Diagnostic::new(
+                violations::UseTernaryOperator("y = 1 if z else 2".to_string()),
+                Range::new(Location::new(2, 0), Location::new(2, 18)),
+            ),
+        ];
+        assert_eq!(code_actions(&diagnostics).len(), 1);
+    }
+}
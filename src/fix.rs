@@ -0,0 +1,171 @@
+use rustpython_parser::ast::Location;
+use serde::{Deserialize, Serialize};
+
+/// The mode in which fixes are applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FixMode {
+    Generate,
+    Apply,
+    #[default]
+    None,
+}
+
+/// Which diagnostics, if any, should have their [`Fix`] eagerly materialized
+/// during the (cheap) lint pass, versus resolved on demand later.
+///
+/// The CLI's `--fix` wants every fix built up front so it can rewrite the
+/// file in one pass; an editor enumerating thousands of diagnostics wants to
+/// pay for a fix's replacement text only when the user actually asks to
+/// apply it. `id` identifies a diagnostic within the set being checked (e.g.
+/// its ordinal position), mirroring an LSP "resolve" request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FixResolution {
+    /// Don't materialize any fixes; diagnostics are emitted with a fix
+    /// capability flag only.
+    #[default]
+    Unresolved,
+    /// Materialize every fix eagerly (the `--fix` CLI path).
+    All,
+    /// Materialize only the fix for the diagnostic identified by `id`.
+    Single(usize),
+}
+
+impl FixResolution {
+    /// Return `true` if the diagnostic identified by `id` should have its
+    /// fix materialized under this resolution mode.
+    #[must_use]
+    pub fn resolves(self, id: usize) -> bool {
+        match self {
+            FixResolution::Unresolved => false,
+            FixResolution::All => true,
+            FixResolution::Single(target) => target == id,
+        }
+    }
+}
+
+/// How confident a fix is, borrowed from clippy's lint applicability model.
+/// Determines whether `--fix` should apply the fix automatically, or whether
+/// it should only be applied when the user opts into a more aggressive
+/// applicability threshold.
+///
+/// Variants are ordered least- to most-confident, so `fix.applicability >=
+/// threshold` is the gate a caller wants: at the default threshold of
+/// `MachineApplicable`, only unambiguously-safe fixes are applied; lowering
+/// the threshold (e.g. to `MaybeIncorrect`) opts into progressively more
+/// speculative rewrites as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Applicability {
+    /// The fix's correctness is unknown or rule-specific. Ranks lowest, so
+    /// only a threshold of `Unspecified` admits it.
+    Unspecified,
+    /// The fix contains placeholder text that a human must fill in before
+    /// the suggestion is valid.
+    HasPlaceholders,
+    /// The fix is probably correct, but may change semantics in edge cases
+    /// (e.g. if an expression being moved or removed has side effects).
+    MaybeIncorrect,
+    /// The fix is unambiguously correct and safe to apply without review.
+    MachineApplicable,
+}
+
+/// The applicability threshold `--fix` uses unless the user configures a
+/// lower (more aggressive) one: only unambiguously-safe fixes are applied.
+pub const DEFAULT_FIX_APPLICABILITY_THRESHOLD: Applicability = Applicability::MachineApplicable;
+
+impl Applicability {
+    /// Return `true` if a fix tagged with this applicability should be
+    /// applied under the given `threshold`, per the CLI/settings gate.
+    #[must_use]
+    pub fn meets_threshold(self, threshold: Applicability) -> bool {
+        self >= threshold
+    }
+}
+
+/// A remediation for a given [`Diagnostic`](crate::registry::Diagnostic).
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct Fix {
+    pub content: String,
+    pub location: Location,
+    pub end_location: Location,
+    pub applicability: Applicability,
+}
+
+impl Fix {
+    /// Create a [`Fix`] that replaces the content between `location` and
+    /// `end_location` with `content`.
+    pub fn replacement(content: String, location: Location, end_location: Location) -> Self {
+        Self {
+            content,
+            location,
+            end_location,
+            applicability: Applicability::Unspecified,
+        }
+    }
+
+    /// Create a [`Fix`] that deletes the content between `location` and
+    /// `end_location`.
+    pub fn deletion(location: Location, end_location: Location) -> Self {
+        Self {
+            content: String::new(),
+            location,
+            end_location,
+            applicability: Applicability::Unspecified,
+        }
+    }
+
+    /// Tag this fix with an [`Applicability`], overriding the default
+    /// `Unspecified` confidence.
+    #[must_use]
+    pub fn with_applicability(mut self, applicability: Applicability) -> Self {
+        self.applicability = applicability;
+        self
+    }
+
+    pub fn is_deletion(&self) -> bool {
+        self.content.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Applicability, FixResolution, DEFAULT_FIX_APPLICABILITY_THRESHOLD};
+
+    #[test]
+    fn unresolved_resolves_nothing() {
+        assert!(!FixResolution::Unresolved.resolves(0));
+        assert!(!FixResolution::Unresolved.resolves(42));
+    }
+
+    #[test]
+    fn all_resolves_everything() {
+        assert!(FixResolution::All.resolves(0));
+        assert!(FixResolution::All.resolves(42));
+    }
+
+    #[test]
+    fn single_resolves_only_its_id() {
+        let resolution = FixResolution::Single(3);
+        assert!(!resolution.resolves(2));
+        assert!(resolution.resolves(3));
+        assert!(!resolution.resolves(4));
+    }
+
+    #[test]
+    fn applicability_orders_by_confidence() {
+        assert!(Applicability::MachineApplicable > Applicability::MaybeIncorrect);
+        assert!(Applicability::MaybeIncorrect > Applicability::HasPlaceholders);
+        assert!(Applicability::HasPlaceholders > Applicability::Unspecified);
+    }
+
+    #[test]
+    fn default_threshold_only_admits_machine_applicable() {
+        assert!(Applicability::MachineApplicable.meets_threshold(DEFAULT_FIX_APPLICABILITY_THRESHOLD));
+        assert!(!Applicability::MaybeIncorrect.meets_threshold(DEFAULT_FIX_APPLICABILITY_THRESHOLD));
+    }
+
+    #[test]
+    fn lowering_the_threshold_admits_less_confident_fixes() {
+        assert!(Applicability::MaybeIncorrect.meets_threshold(Applicability::MaybeIncorrect));
+        assert!(!Applicability::HasPlaceholders.meets_threshold(Applicability::MaybeIncorrect));
+    }
+}
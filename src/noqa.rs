@@ -0,0 +1,127 @@
+//! Parsing of `# noqa` and `# ruff: noqa` directives.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::registry::{RuleCode, CODE_REDIRECTS};
+
+/// A single inline `# noqa` directive.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Directive<'a> {
+    /// No directive on this line.
+    None,
+    /// A bare `# noqa`, silencing every violation on the line. Carries the
+    /// number of leading spaces before the `#`, plus the directive's start
+    /// and end columns.
+    All(usize, usize, usize),
+    /// A `# noqa: E501, F401`, silencing only the listed codes. Carries the
+    /// same position info as `All`, plus the parsed code list.
+    Codes(usize, usize, usize, Vec<&'a str>),
+}
+
+/// A file-level `# ruff: noqa` directive, which applies to the whole file
+/// rather than a single line.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FileExemption<'a> {
+    /// No file-level directive on this line.
+    None,
+    /// A bare `# ruff: noqa`, silencing every violation in the file.
+    All,
+    /// A `# ruff: noqa: E501, F401`, silencing only the listed codes
+    /// file-wide; every other violation is still reported.
+    Codes(Vec<&'a str>),
+}
+
+static NOQA_LINE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)(?P<spaces> *)# noqa(?::[ ]?(?P<codes>[A-Z]+[0-9]+(?:[,\s]+[A-Z]+[0-9]+)*))?")
+        .unwrap()
+});
+
+static FILE_NOQA_LINE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)# ruff: noqa(?::[ ]?(?P<codes>[A-Z]+[0-9]+(?:[,\s]+[A-Z]+[0-9]+)*))?").unwrap()
+});
+
+fn split_codes(codes: &str) -> Vec<&str> {
+    codes
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|code| !code.is_empty())
+        .collect()
+}
+
+/// Extract the [`Directive`] (if any) from a line that's been flagged as
+/// containing a `noqa` comment.
+pub fn extract_noqa_directive(line: &str) -> Directive {
+    let Some(caps) = NOQA_LINE_REGEX.captures(line) else {
+        return Directive::None;
+    };
+    let whole = caps.get(0).unwrap();
+    let spaces = caps.name("spaces").map_or(0, |m| m.as_str().len());
+    let start = whole.start() + spaces;
+    let end = whole.end();
+    match caps.name("codes") {
+        Some(codes) => Directive::Codes(spaces, start, end, split_codes(codes.as_str())),
+        None => Directive::All(spaces, start, end),
+    }
+}
+
+/// Determine whether a line carries a file-level `# ruff: noqa` directive,
+/// and if so, whether it exempts the whole file or only specific codes.
+pub fn is_file_exempt(line: &str) -> FileExemption {
+    let Some(caps) = FILE_NOQA_LINE_REGEX.captures(line) else {
+        return FileExemption::None;
+    };
+    match caps.name("codes") {
+        Some(codes) => FileExemption::Codes(split_codes(codes.as_str())),
+        None => FileExemption::All,
+    }
+}
+
+/// Return `true` if `code` is covered by any of `codes`, accounting for
+/// redirects and prefix matches (e.g. `# noqa: E5` covering `E501`).
+pub fn includes(code: &RuleCode, codes: &[&str]) -> bool {
+    let code: &str = code.as_ref();
+    codes.iter().any(|candidate| {
+        let candidate = CODE_REDIRECTS.get(*candidate).map_or(*candidate, AsRef::as_ref);
+        code.starts_with(candidate)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::RuleCode;
+
+    use super::{extract_noqa_directive, includes, is_file_exempt, Directive, FileExemption};
+
+    #[test]
+    fn file_exempt_all() {
+        assert_eq!(is_file_exempt("# ruff: noqa"), FileExemption::All);
+    }
+
+    #[test]
+    fn file_exempt_codes() {
+        assert_eq!(
+            is_file_exempt("# ruff: noqa: E501, F401"),
+            FileExemption::Codes(vec!["E501", "F401"])
+        );
+    }
+
+    #[test]
+    fn file_exempt_none() {
+        assert_eq!(is_file_exempt("x = 1"), FileExemption::None);
+    }
+
+    #[test]
+    fn directive_codes_parsed() {
+        match extract_noqa_directive("x = 1  # noqa: E501,F401") {
+            Directive::Codes(.., codes) => assert_eq!(codes, vec!["E501", "F401"]),
+            other => panic!("expected Codes, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn includes_is_a_prefix_match() {
+        assert!(includes(&RuleCode::E501, &["E5"]));
+        assert!(includes(&RuleCode::E501, &["E501"]));
+        assert!(!includes(&RuleCode::E501, &["F401"]));
+    }
+}
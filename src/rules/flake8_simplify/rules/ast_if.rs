@@ -8,9 +8,10 @@ use crate::ast::helpers::{
 };
 use crate::ast::types::Range;
 use crate::checkers::ast::Checker;
-use crate::fix::Fix;
+use crate::fix::{Applicability, Fix};
 use crate::registry::{Diagnostic, RuleCode};
 use crate::rules::flake8_simplify::rules::fix_if;
+use crate::settings::per_rule::RuleSettings;
 use crate::violations;
 
 fn is_main_check(expr: &Expr) -> bool {
@@ -75,14 +76,16 @@ pub fn nested_if_statements(checker: &mut Checker, stmt: &Stmt) {
             Range::new(stmt.location, nested_if.location),
             checker.locator,
         ) {
+            let max_line_length = match checker.settings.per_rule.get(&RuleCode::SIM102) {
+                Some(RuleSettings::NestedIf {
+                    max_line_length: Some(max_line_length),
+                }) => *max_line_length,
+                _ => checker.settings.line_length,
+            };
             match fix_if::fix_nested_if_statements(checker.locator, stmt) {
                 Ok(fix) => {
-                    if fix
-                        .content
-                        .lines()
-                        .all(|line| line.len() <= checker.settings.line_length)
-                    {
-                        diagnostic.amend(fix);
+                    if fix.content.lines().all(|line| line.len() <= max_line_length) {
+                        diagnostic.amend(fix.with_applicability(Applicability::MachineApplicable));
                     }
                 }
                 Err(err) => error!("Failed to fix nested if: {err}"),
@@ -125,11 +128,14 @@ pub fn return_bool_condition_directly(checker: &mut Checker, stmt: &Stmt) {
         let return_stmt = create_stmt(StmtKind::Return {
             value: Some(test.clone()),
         });
-        diagnostic.amend(Fix::replacement(
-            unparse_stmt(&return_stmt, checker.stylist),
-            stmt.location,
-            orelse[0].end_location.unwrap(),
-        ));
+        diagnostic.amend(
+            Fix::replacement(
+                unparse_stmt(&return_stmt, checker.stylist),
+                stmt.location,
+                orelse[0].end_location.unwrap(),
+            )
+            .with_applicability(Applicability::MachineApplicable),
+        );
     }
     checker.diagnostics.push(diagnostic);
 }
@@ -173,14 +179,23 @@ pub fn use_ternary_operator(checker: &mut Checker, stmt: &Stmt, parent: Option<&
         return;
     }
 
-    // Avoid suggesting ternary for `if sys.version_info >= ...`-style checks.
-    if contains_call_path(checker, test, &["sys", "version_info"]) {
-        return;
+    // Avoid suggesting ternary for `if sys.version_info >= ...`-style checks
+    // and `if sys.platform.startswith("...")`-style checks, plus any
+    // additional call paths configured for SIM108.
+    let mut ignore_call_paths = vec![
+        vec!["sys".to_string(), "version_info".to_string()],
+        vec!["sys".to_string(), "platform".to_string()],
+    ];
+    if let Some(RuleSettings::Sim108 {
+        ignore_call_paths: configured,
+    }) = checker.settings.per_rule.get(&RuleCode::SIM108)
+    {
+        ignore_call_paths.extend(configured.iter().cloned());
     }
-
-    // Avoid suggesting ternary for `if sys.platform.startswith("...")`-style
-    // checks.
-    if contains_call_path(checker, test, &["sys", "platform"]) {
+    if ignore_call_paths.iter().any(|call_path| {
+        let call_path: Vec<&str> = call_path.iter().map(String::as_str).collect();
+        contains_call_path(checker, test, &call_path)
+    }) {
         return;
     }
 
@@ -233,11 +248,17 @@ pub fn use_ternary_operator(checker: &mut Checker, stmt: &Stmt, parent: Option<&
         Range::from_located(stmt),
     );
     if checker.patch(&RuleCode::SIM108) {
-        diagnostic.amend(Fix::replacement(
-            contents,
-            stmt.location,
-            stmt.end_location.unwrap(),
-        ));
+        // Ternary rewrites can change formatting, so they're only ever
+        // "maybe correct" rather than machine-applicable, and are only
+        // materialized if the user has opted into that applicability.
+        let fix = Fix::replacement(contents, stmt.location, stmt.end_location.unwrap())
+            .with_applicability(Applicability::MaybeIncorrect);
+        if fix
+            .applicability
+            .meets_threshold(checker.settings.fix_applicability_threshold)
+        {
+            diagnostic.amend(fix);
+        }
     }
     checker.diagnostics.push(diagnostic);
 }
@@ -296,8 +317,15 @@ pub fn use_dict_get_with_default(
         return;
     }
 
-    // Check that the default value is not "complex".
-    if contains_effect(checker, default_val) {
+    // Check that the default value is not "complex", unless the user has
+    // opted out of the side-effect check for SIM401.
+    let require_side_effect_free_default = match checker.settings.per_rule.get(&RuleCode::SIM401) {
+        Some(RuleSettings::Sim401 {
+            require_side_effect_free_default,
+        }) => *require_side_effect_free_default,
+        _ => true,
+    };
+    if require_side_effect_free_default && contains_effect(checker, default_val) {
         return;
     }
 
@@ -336,11 +364,17 @@ pub fn use_dict_get_with_default(
         Range::from_located(stmt),
     );
     if checker.patch(&RuleCode::SIM401) {
-        diagnostic.amend(Fix::replacement(
-            contents,
-            stmt.location,
-            stmt.end_location.unwrap(),
-        ));
+        // Could change semantics if the dict access had side effects, so
+        // this is only ever a "maybe correct" suggestion, materialized only
+        // if the user has opted into that applicability.
+        let fix = Fix::replacement(contents, stmt.location, stmt.end_location.unwrap())
+            .with_applicability(Applicability::MaybeIncorrect);
+        if fix
+            .applicability
+            .meets_threshold(checker.settings.fix_applicability_threshold)
+        {
+            diagnostic.amend(fix);
+        }
     }
     checker.diagnostics.push(diagnostic);
 }
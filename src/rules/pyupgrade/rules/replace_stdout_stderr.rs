@@ -4,7 +4,7 @@ use crate::ast::helpers::find_keyword;
 use crate::ast::types::Range;
 use crate::ast::whitespace::indentation;
 use crate::checkers::ast::Checker;
-use crate::fix::Fix;
+use crate::fix::{Applicability, Fix};
 use crate::registry::Diagnostic;
 use crate::source_code::Locator;
 use crate::violations;
@@ -71,11 +71,10 @@ fn generate_fix(locator: &Locator, stdout: &Keyword, stderr: &Keyword) -> Option
         }
         contents.push_str(middle.contents);
     }
-    Some(Fix::replacement(
-        contents,
-        first.location,
-        last.end_location.unwrap(),
-    ))
+    Some(
+        Fix::replacement(contents, first.location, last.end_location.unwrap())
+            .with_applicability(Applicability::MachineApplicable),
+    )
 }
 
 /// UP022
@@ -0,0 +1,148 @@
+//! Per-rule configuration, modeled on `clippy.toml`'s configurable lints.
+//!
+//! Most rules are tuned globally (e.g. via `line-length`), but a handful need
+//! rule-specific knobs -- an additional call path to ignore, whether a
+//! side-effect check is required, etc. Those live here, keyed by the
+//! [`RuleCode`] they configure, rather than as ad-hoc fields scattered across
+//! individual rule functions.
+
+use std::collections::HashMap;
+
+use crate::registry::RuleCode;
+
+/// Rule-specific configuration. One variant per rule that exposes tunable
+/// behavior.
+#[derive(Debug, Clone)]
+pub enum RuleSettings {
+    /// SIM108: additional call path prefixes (beyond `sys.version_info` and
+    /// `sys.platform`) for which the ternary rewrite should be skipped.
+    Sim108 { ignore_call_paths: Vec<Vec<String>> },
+    /// SIM401: whether the `dict.get` rewrite requires the default value to
+    /// be free of side effects.
+    Sim401 {
+        require_side_effect_free_default: bool,
+    },
+    /// SIM102: an independent max line length for the generated fix,
+    /// overriding the global `line-length` setting.
+    NestedIf { max_line_length: Option<usize> },
+}
+
+/// A typed, per-rule configuration map, keyed by [`RuleCode`].
+#[derive(Debug, Clone, Default)]
+pub struct PerRuleSettings(HashMap<RuleCode, RuleSettings>);
+
+impl PerRuleSettings {
+    pub fn get(&self, code: &RuleCode) -> Option<&RuleSettings> {
+        self.0.get(code)
+    }
+
+    pub fn insert(&mut self, code: RuleCode, settings: RuleSettings) {
+        self.0.insert(code, settings);
+    }
+
+    /// Build a [`PerRuleSettings`] from the Flake8 options that have a
+    /// direct Ruff per-rule equivalent, as parsed out of a Flake8 INI file's
+    /// `[flake8]` section. Options that aren't present (or don't parse) are
+    /// simply omitted, leaving the affected rule's hard-coded default in
+    /// place. `flake8_to_ruff::convert` does not call this, so the
+    /// `flake8-to-ruff` binary does not carry these options over.
+    #[must_use]
+    pub fn from_flake8_options(flake8: &HashMap<String, HashMap<String, Option<String>>>) -> Self {
+        let mut settings = Self::default();
+
+        let Some(section) = flake8.get("flake8") else {
+            return settings;
+        };
+
+        if let Some(Some(value)) = section.get("simplify-ignore-call-paths") {
+            let ignore_call_paths = value
+                .split(',')
+                .map(str::trim)
+                .filter(|path| !path.is_empty())
+                .map(|path| path.split('.').map(str::to_string).collect())
+                .collect();
+            settings.insert(
+                RuleCode::SIM108,
+                RuleSettings::Sim108 { ignore_call_paths },
+            );
+        }
+
+        if let Some(Some(value)) = section.get("simplify-allow-side-effects-in-default") {
+            if let Ok(allow_side_effects) = value.parse::<bool>() {
+                settings.insert(
+                    RuleCode::SIM401,
+                    RuleSettings::Sim401 {
+                        require_side_effect_free_default: !allow_side_effects,
+                    },
+                );
+            }
+        }
+
+        if let Some(Some(value)) = section.get("simplify-max-fix-line-length") {
+            if let Ok(max_line_length) = value.parse::<usize>() {
+                settings.insert(
+                    RuleCode::SIM102,
+                    RuleSettings::NestedIf {
+                        max_line_length: Some(max_line_length),
+                    },
+                );
+            }
+        }
+
+        settings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PerRuleSettings, RuleSettings};
+    use crate::registry::RuleCode;
+    use std::collections::HashMap;
+
+    fn flake8_section(pairs: &[(&str, &str)]) -> HashMap<String, HashMap<String, Option<String>>> {
+        let mut section = HashMap::new();
+        for (key, value) in pairs {
+            section.insert((*key).to_string(), Some((*value).to_string()));
+        }
+        let mut flake8 = HashMap::new();
+        flake8.insert("flake8".to_string(), section);
+        flake8
+    }
+
+    #[test]
+    fn from_flake8_options_parses_sim108_ignore_call_paths() {
+        let flake8 = flake8_section(&[("simplify-ignore-call-paths", "foo.bar, baz.qux")]);
+        let settings = PerRuleSettings::from_flake8_options(&flake8);
+        match settings.get(&RuleCode::SIM108) {
+            Some(RuleSettings::Sim108 { ignore_call_paths }) => {
+                assert_eq!(
+                    ignore_call_paths,
+                    &vec![
+                        vec!["foo".to_string(), "bar".to_string()],
+                        vec!["baz".to_string(), "qux".to_string()],
+                    ]
+                );
+            }
+            other => panic!("expected Sim108, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn from_flake8_options_parses_sim401_side_effects() {
+        let flake8 = flake8_section(&[("simplify-allow-side-effects-in-default", "true")]);
+        let settings = PerRuleSettings::from_flake8_options(&flake8);
+        match settings.get(&RuleCode::SIM401) {
+            Some(RuleSettings::Sim401 {
+                require_side_effect_free_default,
+            }) => assert!(!require_side_effect_free_default),
+            other => panic!("expected Sim401, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn from_flake8_options_ignores_absent_section() {
+        let flake8 = HashMap::new();
+        let settings = PerRuleSettings::from_flake8_options(&flake8);
+        assert!(settings.get(&RuleCode::SIM108).is_none());
+    }
+}
@@ -0,0 +1,60 @@
+//! Graded diagnostic severity, mirroring the error/warning/info model used by
+//! editor diagnostics pipelines.
+
+use serde::{Deserialize, Serialize};
+
+use crate::registry::RuleCode;
+use crate::settings::Settings;
+
+/// How severe a diagnostic is. Defaults are assigned per rule, but can be
+/// overridden in `Settings`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+/// The severity a rule carries unless the user has overridden it.
+///
+/// RUF100 (unused-noqa bookkeeping) defaults to `Warning`; every other rule
+/// defaults to `Error`, matching today's behavior where every violation
+/// contributes to a nonzero exit code.
+pub fn default_severity(code: &RuleCode) -> Severity {
+    match code {
+        RuleCode::RUF100 => Severity::Warning,
+        _ => Severity::Error,
+    }
+}
+
+/// Resolve the effective severity for `code`, honoring any per-rule override
+/// configured in `Settings` and falling back to [`default_severity`].
+pub fn severity_for(code: &RuleCode, settings: &Settings) -> Severity {
+    settings
+        .severity_overrides
+        .get(code)
+        .copied()
+        .unwrap_or_else(|| default_severity(code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{default_severity, Severity};
+    use crate::registry::RuleCode;
+
+    #[test]
+    fn severity_orders_info_below_warning_below_error() {
+        assert!(Severity::Error > Severity::Warning);
+        assert!(Severity::Warning > Severity::Info);
+    }
+
+    #[test]
+    fn ruf100_defaults_to_warning() {
+        assert_eq!(default_severity(&RuleCode::RUF100), Severity::Warning);
+    }
+
+    #[test]
+    fn other_rules_default_to_error() {
+        assert_eq!(default_severity(&RuleCode::SIM102), Severity::Error);
+    }
+}